@@ -3,6 +3,9 @@
 //! * `match_all` - This provides a side-effect purposed match statement that allows all matching patterns to execute their associated expressions
 //! * `for_match_all` - This provides a side-effect purposed match statement that allows all matching patterns to execute their associated expressions while looping through a `.iter()` value
 //! * `for_match` - This provides a side-effect purposed match statement that allows all matching patterns to execute their associated expressions
+//! * `match_iter` - This provides positional, variadic destructuring of an iterator, complementing the value-oriented `match_all`
+//! * `match_all_collect` - This provides an expression-position variant of `match_all!` that collects every matched arm's value into a `Vec`
+//! * `match_iter_typed` - This provides a typed layer over `match_iter!` that converts each positional capture into an expected type
 
 /// # match_all
 /// Provides the `match_all!` macro for rust
@@ -20,6 +23,7 @@
 /// * `value`: the expression to match on.
 /// * `IfNoMatch`: the expression after this is executed if none of the other patterns are matched. This branch is optional.
 /// * `pat | pat ...`: this is groupings of patterns that will be checked. If any of them match to value then their corresponding expression is executed. After checking a group of patterns then the next group is checked until all groups have been checked. If none match then the `IfNoMatch` expression will be executed.
+/// * `if guard`: an optional guard expression following a group of patterns. If present, the group only counts as matched when `value` matches one of the patterns and `guard` evaluates to `true`.
 ///
 /// ## Example One
 ///
@@ -105,13 +109,13 @@
 
 #[macro_export]
 macro_rules! match_all {
-   ($val:expr, IfNoMatch => $c:expr, $($($p:pat)|+ => $b:expr),+) => {{
+   ($val:expr, IfNoMatch => $c:expr, $($($p:pat)|+ $(if $guard:expr)? => $b:expr),+) => {{
         let val = $val;
         let mut matched = false;
         $(
             #[allow(unreachable_patterns)]
             match val {
-                $($p)|+ => {
+                $($p)|+ $(if $guard)? => {
                     $b;
                     matched = true;
                 },
@@ -122,22 +126,67 @@ macro_rules! match_all {
             $c
         }
    }};
-   ($val:expr, $($($p:pat)|+ => $b:expr),+) => {{
+   ($val:expr, $($($p:pat)|+ $(if $guard:expr)? => $b:expr),+) => {{
         let val = $val;
         $(
             #[allow(unreachable_patterns)]
             match val {
-                $($p)|+ => { $b; },
+                $($p)|+ $(if $guard)? => { $b; },
                 _ => (),
             }
         )+
    }};
 }
 
+/// # match_all_collect
+/// Provides the `match_all_collect!` macro for rust
+///
+/// This is the expression-position companion to `match_all!`. Instead of discarding the value of each matched arm, it gathers the value of every matched arm's expression into a `Vec` that becomes the value of the macro block.
+///
+/// ## Format
+///
+///     match_all_collect!{ value,
+///         pat | pat ... => expr,
+///         ...
+///     }
+///
+/// * `value`: the expression to match on.
+/// * `pat | pat ...`: this is groupings of patterns that will be checked. If any of them match to value then their corresponding expression is pushed onto the result `Vec`. After checking a group of patterns then the next group is checked until all groups have been checked.
+/// * `if guard`: an optional guard expression following a group of patterns, as in `match_all!`.
+///
+/// ## Example One
+///
+///     let results = match_all_collect!{ Some(4),
+///         Some(3) | Some(4) => "a",
+///         Some(4) | Some(5) => "b"
+///     };
+///
+/// This would make `results` equal to:
+///
+///     vec!["a", "b"]
+
+#[macro_export]
+macro_rules! match_all_collect {
+   ($val:expr, $($($p:pat)|+ $(if $guard:expr)? => $b:expr),+) => {{
+        let val = $val;
+        let mut results = ::std::vec::Vec::new();
+        $(
+            #[allow(unreachable_patterns)]
+            match val {
+                $($p)|+ $(if $guard)? => {
+                    results.push($b);
+                },
+                _ => (),
+            }
+        )+
+        results
+   }};
+}
+
 /// # for_match_all
 /// Provides the `for_match_all!` macro for rust
 ///
-/// This macro combines the functionality of a for loop and the `match_all!`
+/// This macro combines the functionality of a for loop and the `match_all!`. It matches on a reference to each item, so it works for non-`Copy` element types such as `String` as well.
 ///
 /// ## Format
 ///
@@ -151,6 +200,7 @@ macro_rules! match_all {
 /// * `arr`: an expression that has the `.iter()` method, this holds the values to iterate through
 /// * `IfNoMatch`: the expression after this is executed if none of the other patterns are matched. This branch is optional.
 /// * `pat | pat ...`: this is groupings of patterns that will be checked. If any of them match to value then their corresponding expression is executed. After checking a group of patterns then the next group is checked until all groups have been checked. If none match then the `IfNoMatch` expression will be executed.
+/// * `if guard`: an optional guard expression following a group of patterns. If present, the group only counts as matched when `ident` matches one of the patterns and `guard` evaluates to `true`.
 ///
 /// ## Example One
 ///
@@ -170,14 +220,13 @@ macro_rules! match_all {
 
 #[macro_export]
 macro_rules! for_match_all {
-   ($var:ident in $val:expr, IfNoMatch => $c:expr, $($($p:pat)|+ => $b:expr),+) => {{
+   ($var:ident in $val:expr, IfNoMatch => $c:expr, $($($p:pat)|+ $(if $guard:expr)? => $b:expr),+) => {{
     for $var in $val.iter() {
         let mut matched = false;
-        let var = *$var;
         $(
             #[allow(unreachable_patterns)]
-            match var {
-                $($p)|+ => {
+            match $var {
+                $($p)|+ $(if $guard)? => {
                     $b;
                     matched = true;
                 },
@@ -189,13 +238,12 @@ macro_rules! for_match_all {
         }
     }
    }};
-   ($var:ident in $val:expr, $($($p:pat)|+ => $b:expr),+) => {{
+   ($var:ident in $val:expr, $($($p:pat)|+ $(if $guard:expr)? => $b:expr),+) => {{
      for $var in $val.iter() {
-        let var = *$var;
         $(
             #[allow(unreachable_patterns)]
-            match var {
-                $($p)|+ => { $b; },
+            match $var {
+                $($p)|+ $(if $guard)? => { $b; },
                 _ => (),
             }
         )+
@@ -206,7 +254,7 @@ macro_rules! for_match_all {
 /// # for_match
 /// Provides the `for_match!` macro for rust
 ///
-/// This macro combines the functionality of a `for` loop and a `match` statement. So it iterates through each element in the expression and calls match on it
+/// This macro combines the functionality of a `for` loop and a `match` statement. So it iterates through each element in the expression and calls match on it. It matches on a reference to each item, so it works for non-`Copy` element types such as `String` as well.
 ///
 /// ## Format
 ///
@@ -218,6 +266,7 @@ macro_rules! for_match_all {
 /// * `arr`: an expression that has the `.iter()` method, this holds the values to iterate through
 /// * `IfNoMatch`: the expression after this is executed if none of the other patterns are matched. This branch is optional.
 /// * `pat | pat ...`: this is groupings of patterns that will be checked. If any of them match to value then their corresponding expression is executed. After checking a group of patterns then the next group is checked until all groups have been checked. If none match then the `IfNoMatch` expression will be executed.
+/// * `if guard`: an optional guard expression following a group of patterns. If present, the group only counts as matched when `ident` matches one of the patterns and `guard` evaluates to `true`.
 ///
 /// ## Example One
 ///
@@ -237,14 +286,219 @@ macro_rules! for_match_all {
 
 #[macro_export]
 macro_rules! for_match {
-   ($var:ident in $val:expr, $($($p:pat)|+ => $b:expr),+) => {{
+   ($var:ident in $val:expr, $($($p:pat)|+ $(if $guard:expr)? => $b:expr),+) => {{
     for $var in $val.iter() {
-        let var = *$var;
         #[allow(unreachable_patterns)]
-        match var {
-            $($($p)|+ => { $b; }),+
+        match $var {
+            $($($p)|+ $(if $guard)? => { $b; }),+
             _ => (),
         }
     }
    }};
 }
+
+/// Error returned by the `@get_err` form of [`match_iter!`] instead of panicking.
+///
+/// * `NotEnoughItems`: the iterator ran out of items before all of the fixed-position bindings were filled.
+/// * `TooManyItems`: the iterator still had items left over after the fixed-position bindings were filled.
+/// * `NoMatchFound`: reserved for matchers built on top of `match_iter!` that perform their own matching on top of the positional consumption.
+/// * `Other(T)`: carries an error type of the caller's choosing. `match_iter!` and `match_iter_typed!` never return it, as their error type is `IterMatchError<::std::convert::Infallible>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IterMatchError<T> {
+    NotEnoughItems,
+    TooManyItems,
+    NoMatchFound,
+    Other(T),
+}
+
+/// # match_iter
+/// Provides the `match_iter!` macro for rust
+///
+/// This macro structurally matches an iterator (or anything that has an `.into_iter()`) by position, complementing the value-oriented `match_all!`.
+///
+/// ## Format
+///
+///     match_iter!(iter; (ident, ident?, ident*) => expr)
+///
+/// * `iter`: an expression that has an `.into_iter()` method, this holds the values to destructure.
+/// * `ident`: binds the next item from the iterator (`T`). Panics if the iterator is exhausted.
+/// * `ident?`: binds the next item as an `Option<T>`, consuming an item only if one is present.
+/// * `ident*`: binds the rest of the iterator (`impl Iterator<Item = T>`). If present, must be the last binding.
+///
+/// ## Example One
+///
+///     let v = vec![1, 2, 3, 4];
+///
+///     match_iter!(v; (a, b, rest*) => {
+///         println!("{} {} {:?}", a, b, rest.collect::<Vec<_>>());
+///     });
+///
+/// This would print:
+///
+///     1 2 [3, 4]
+///
+/// Panics if the iterator yields fewer items than the required bindings, or if it has items left over after a fixed-length binding list with no `ident*`.
+///
+/// ## Fallible Form
+///
+///     match_iter!(@get_err, iter; (ident, ident?, ident*) => expr)
+///
+/// Returns `Result<_, IterMatchError<_>>` instead of panicking, so callers that parse untrusted input can recover from a short or long iterator rather than aborting.
+
+/// Error returned by the `@get_err` form of [`match_iter!`] instead of panicking.
+///
+/// * `NotEnoughItems`: the iterator ran out of items before all of the fixed-position bindings were filled.
+/// * `TooManyItems`: the iterator still had items left over after the fixed-position bindings were filled.
+/// * `NoMatchFound`: reserved for matchers built on top of `match_iter!` that perform their own matching on top of the positional consumption.
+/// * `Other(T)`: carries an error type of the caller's choosing. `match_iter!` and `match_iter_typed!` never return it, as their error type is `IterMatchError<::std::convert::Infallible>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IterMatchError<T> {
+    NotEnoughItems,
+    TooManyItems,
+    NoMatchFound,
+    Other(T),
+}
+
+#[macro_export]
+macro_rules! match_iter {
+    ($iter:expr; ($($toks:tt)*) => $body:expr) => {
+        $crate::match_iter!(@get_err, $iter; ($($toks)*) => $body)
+            .expect("match_iter!: pattern did not match iterator")
+    };
+    (@get_err, $iter:expr; ($($toks:tt)*) => $body:expr) => {{
+        (|| -> ::std::result::Result<_, $crate::IterMatchError<::std::convert::Infallible>> {
+            let mut iter = ::std::iter::IntoIterator::into_iter($iter);
+            $crate::__match_iter_munch!(iter; ($($toks)*));
+            ::std::result::Result::Ok($body)
+        })()
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __match_iter_munch {
+    ($iter:ident; ($name:ident * $(,)?)) => {
+        let mut $name = $iter;
+    };
+    ($iter:ident; ($name:ident ? $(, $($rest:tt)*)?)) => {
+        let $name = $iter.next();
+        $crate::__match_iter_munch!($iter; ($($($rest)*)?));
+    };
+    ($iter:ident; ($name:ident $(, $($rest:tt)*)?)) => {
+        let $name = match $iter.next() {
+            ::std::option::Option::Some(__v) => __v,
+            ::std::option::Option::None => return ::std::result::Result::Err($crate::IterMatchError::NotEnoughItems),
+        };
+        $crate::__match_iter_munch!($iter; ($($($rest)*)?));
+    };
+    ($iter:ident; ()) => {
+        if $iter.next().is_some() {
+            return ::std::result::Result::Err($crate::IterMatchError::TooManyItems);
+        }
+    };
+}
+
+/// # match_iter_typed
+/// Provides the `match_iter_typed!` macro for rust
+///
+/// This is a typed layer over `match_iter!`. Each positional binding carries an expected type, and the captured item is converted into it via `TryInto` before the body runs. This is useful for AST-builder/parser style code where each child of a node has a known kind.
+///
+/// ## Format
+///
+///     match_iter_typed!(iter; (ident: Type, ident?: Type, ident*: Type) => expr)
+///
+/// * `iter`: an expression that has an `.into_iter()` method, this holds the values to destructure.
+/// * `ident: Type`: binds the next item, converted via `TryInto<Type>`.
+/// * `ident?: Type`: binds the next item as an `Option<Type>`, converted via `TryInto<Type>` if an item is present.
+/// * `ident*: Type`: binds the rest of the iterator, eagerly converted into a `Vec<Type>` via `TryInto<Type>`. If present, must be the last binding.
+///
+/// Positional consumption is shared with `match_iter!`; a conversion failure is reported the same way a failed match is, via `IterMatchError::NoMatchFound`.
+///
+/// ## Example One
+///
+///     let v: Vec<i64> = vec![1, 2, 3];
+///
+///     match_iter_typed!(v; (a: u8, rest*: u8) => {
+///         println!("{} {:?}", a, rest);
+///     });
+///
+/// This would print:
+///
+///     1 [2, 3]
+///
+/// Panics if the iterator does not have enough/has too many items, or if any item fails its `TryInto` conversion.
+///
+/// ## Fallible Form
+///
+///     match_iter_typed!(@get_err, iter; (ident: Type, ident?: Type, ident*: Type) => expr)
+///
+/// Returns `Result<_, IterMatchError<::std::convert::Infallible>>` instead of panicking.
+
+#[macro_export]
+macro_rules! match_iter_typed {
+    ($iter:expr; ($($toks:tt)*) => $body:expr) => {
+        $crate::match_iter_typed!(@get_err, $iter; ($($toks)*) => $body)
+            .expect("match_iter_typed!: pattern did not match or convert iterator")
+    };
+    (@get_err, $iter:expr; ($($toks:tt)*) => $body:expr) => {{
+        $crate::__match_iter_typed_munch!(@names () @conv () ($($toks)*) => $iter; $body)
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __match_iter_typed_munch {
+    (@names ($($names:tt)*) @conv ($($conv:tt)*) ($name:ident * : $ty:ty $(, $($rest:tt)*)?) => $iter:expr; $body:expr) => {
+        $crate::__match_iter_typed_munch!(
+            @names ($($names)* $name *,)
+            @conv ($($conv)*
+                let $name: ::std::vec::Vec<$ty> = {
+                    let mut __typed = ::std::vec::Vec::new();
+                    for __item in $name {
+                        match ::std::convert::TryInto::try_into(__item) {
+                            ::std::result::Result::Ok(__v) => __typed.push(__v),
+                            ::std::result::Result::Err(_) => return ::std::result::Result::Err($crate::IterMatchError::NoMatchFound),
+                        }
+                    }
+                    __typed
+                };
+            )
+            ($($($rest)*)?) => $iter; $body
+        )
+    };
+    (@names ($($names:tt)*) @conv ($($conv:tt)*) ($name:ident ? : $ty:ty $(, $($rest:tt)*)?) => $iter:expr; $body:expr) => {
+        $crate::__match_iter_typed_munch!(
+            @names ($($names)* $name ?,)
+            @conv ($($conv)*
+                let $name: ::std::option::Option<$ty> = match $name {
+                    ::std::option::Option::Some(__item) => match ::std::convert::TryInto::try_into(__item) {
+                        ::std::result::Result::Ok(__v) => ::std::option::Option::Some(__v),
+                        ::std::result::Result::Err(_) => return ::std::result::Result::Err($crate::IterMatchError::NoMatchFound),
+                    },
+                    ::std::option::Option::None => ::std::option::Option::None,
+                };
+            )
+            ($($($rest)*)?) => $iter; $body
+        )
+    };
+    (@names ($($names:tt)*) @conv ($($conv:tt)*) ($name:ident : $ty:ty $(, $($rest:tt)*)?) => $iter:expr; $body:expr) => {
+        $crate::__match_iter_typed_munch!(
+            @names ($($names)* $name ,)
+            @conv ($($conv)*
+                let $name: $ty = match ::std::convert::TryInto::try_into($name) {
+                    ::std::result::Result::Ok(__v) => __v,
+                    ::std::result::Result::Err(_) => return ::std::result::Result::Err($crate::IterMatchError::NoMatchFound),
+                };
+            )
+            ($($($rest)*)?) => $iter; $body
+        )
+    };
+    (@names ($($names:tt)*) @conv ($($conv:tt)*) () => $iter:expr; $body:expr) => {{
+        (|| -> ::std::result::Result<_, $crate::IterMatchError<::std::convert::Infallible>> {
+            let mut __iter = ::std::iter::IntoIterator::into_iter($iter);
+            $crate::__match_iter_munch!(__iter; ($($names)*));
+            $($conv)*
+            ::std::result::Result::Ok($body)
+        })()
+    }};
+}